@@ -0,0 +1,30 @@
+use std::io::IoError;
+
+/// The specific reason a `Lexer` or `Parser` rejected the input
+#[derive(Show)]
+pub enum ParserErrorKind {
+    /// Stream ended while a token was still expected
+    UnexpectedEOF,
+    /// A character was encountered that is not valid at that point: where no
+    /// token could start, or inside a string or identifier escape sequence
+    InvalidCharacter(char),
+    /// Propagated failure from the underlying `Buffer`
+    UnderlyingError(IoError),
+    /// A string literal's closing `"` was never found; carries the line/column
+    /// the opening `"` was read at
+    UnterminatedString(usize, usize),
+    /// A `#b`/`#o`/`#d`/`#x`/`#e`/`#i`-prefixed or bare numeric literal did not
+    /// match the number grammar; carries the text accumulated so far
+    InvalidNumericLiteral(String),
+    /// A `#\<name>` character literal's name wasn't a known named character
+    /// or a valid `xHH...` hex scalar value; carries the offending name
+    InvalidCharacterName(String),
+}
+
+/// A lexical or syntactic error, tagged with the position it was detected at
+#[derive(Show)]
+pub struct ParserError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParserErrorKind
+}
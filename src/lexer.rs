@@ -2,6 +2,10 @@ use std::io::{IoError, IoErrorKind};
 use std::string::CowString;
 use std::borrow::Cow;
 use std::fmt;
+use std::mem;
+
+use unicode_xid::UnicodeXID;
+use unicode_normalization::UnicodeNormalization;
 
 use error::{ParserError, ParserErrorKind};
 
@@ -19,9 +23,30 @@ pub enum Token {
     True,
     /// `#f`
     False,
-    /// `#\<String>`
-    Character(String),
+    /// `#\<name>`, resolved to the code point it names
+    Character(char),
     Numeric(String),
+    /// A string literal, with the `String` holding the unescaped contents and
+    /// the `bool` recording whether any escape sequence was seen, so later
+    /// stages know whether the literal needs re-quoting to round-trip
+    Str(String, bool),
+    /// `'`
+    Quote,
+    /// `` ` ``
+    Quasiquote,
+    /// `,`
+    Unquote,
+    /// `,@`
+    UnquoteSplicing,
+    /// `#(`, closed by a `CloseParen`
+    OpenVector,
+    /// `#vu8(`, closed by a `CloseParen`
+    OpenBytevector,
+    /// `#;`, consumed by the parser to discard the following datum
+    DatumComment,
+    /// Synthetic token standing in for text `lex_token_recovering` had to discard
+    /// after a lexical error; the error itself is available from `take_errors`
+    Error,
     /// End of character stream
     EOF
 }
@@ -35,8 +60,17 @@ impl fmt::Show for Token {
             Token::Identifier(ref name) => write!(f, "Identifier({})", name),
             Token::True => write!(f, "#t"),
             Token::False => write!(f, "#f"),
-            Token::Character(ref name) => write!(f, "#\\{}", name),
+            Token::Character(c) => write!(f, "#\\{}", c),
             Token::Numeric(ref rep) => rep.fmt(f),
+            Token::Str(ref s, _) => write!(f, "{:?}", s),
+            Token::Quote => write!(f, "'"),
+            Token::Quasiquote => write!(f, "`"),
+            Token::Unquote => write!(f, ","),
+            Token::UnquoteSplicing => write!(f, ",@"),
+            Token::OpenVector => write!(f, "#("),
+            Token::OpenBytevector => write!(f, "#vu8("),
+            Token::DatumComment => write!(f, "#;"),
+            Token::Error => write!(f, "Error"),
             Token::EOF => write!(f, "EOF"),
         }
     }
@@ -65,9 +99,40 @@ fn is_whitespace(c: char) -> bool {
     }
 }
 
+fn is_intraline_whitespace(c: char) -> bool {
+    match c {
+        ' ' | '\t' => true,
+        _ => false
+    }
+}
+
 fn is_initial(c: char) -> bool {
     match c {
-        'a'...'z' | 'A'...'Z' | '!' | '$' | '%' | '&' | '*' | '/' | ':' | '<' | '=' | '>' | '?' | '^' | '_' | '~' => true,
+        '!' | '$' | '%' | '&' | '*' | '/' | ':' | '<' | '=' | '>' | '?' | '^' | '_' | '~' => true,
+        _ => UnicodeXID::is_xid_start(c)
+    }
+}
+
+fn radix_for(c: char) -> Option<u32> {
+    match c {
+        'b' | 'B' => Some(2),
+        'o' | 'O' => Some(8),
+        'd' | 'D' => Some(10),
+        'x' | 'X' => Some(16),
+        _ => None
+    }
+}
+
+fn is_exactness_marker(c: char) -> bool {
+    match c {
+        'e' | 'E' | 'i' | 'I' => true,
+        _ => false
+    }
+}
+
+fn is_exponent_marker(c: char) -> bool {
+    match c {
+        'e' | 'E' | 's' | 'S' | 'f' | 'F' | 'd' | 'D' | 'l' | 'L' => true,
         _ => false
     }
 }
@@ -77,8 +142,8 @@ fn is_subsequent(c: char) -> bool {
         true
     } else {
         match c {
-            '0'...'9' | '+' | '-' | '.' | '@' => true,
-            _ => false
+            '+' | '-' | '.' | '@' => true,
+            _ => UnicodeXID::is_xid_continue(c)
         }
     }
 }
@@ -89,6 +154,11 @@ pub struct Lexer<'a> {
     column: usize,
     stream: &'a mut (Buffer+'a),
     lookahead_buf: Option<char>,
+    /// Characters put back by `unconsume`, most-recently-pushed first;
+    /// `consume` drains this before touching `lookahead_buf` or the stream
+    pushback: Vec<char>,
+    /// Errors recorded by `lex_token_recovering`, drained by `take_errors`
+    errors: Vec<ParserError>,
 }
 
 impl <'a> Lexer<'a> {
@@ -99,9 +169,50 @@ impl <'a> Lexer<'a> {
             column: 1,
             stream: stream,
             lookahead_buf: None,
+            pushback: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Drains and returns every error collected so far by `lex_token_recovering`
+    pub fn take_errors(&mut self) -> Vec<ParserError> {
+        mem::replace(&mut self.errors, Vec::new())
+    }
+
+    /// Lexes the next token like `lex_token`, but recovers from lexical errors
+    /// (`InvalidCharacter`, `InvalidNumericLiteral`, `UnterminatedString`) instead
+    /// of aborting the whole stream: the error is recorded for `take_errors`, the
+    /// offending text is skipped up to the next plausible token boundary
+    /// (whitespace, `(`, `)`, or a `;` comment), and a synthetic `Token::Error` is
+    /// returned in its place so the caller can keep lexing. Any other error
+    /// (an underlying I/O failure, `UnexpectedEOF`, `InvalidCharacterName`) is
+    /// returned as `Err` just as `lex_token` returns it, and the end of the
+    /// stream is still reported as `Token::EOF`.
+    pub fn lex_token_recovering(&mut self) -> Result<TokenWrapper, ParserError> {
+        match self.lex_token() {
+            Ok(t) => Ok(t),
+            Err(e) => match e.kind {
+                ParserErrorKind::InvalidCharacter(_) |
+                ParserErrorKind::InvalidNumericLiteral(_) |
+                ParserErrorKind::UnterminatedString(..) => {
+                    let line = e.line;
+                    let col = e.column;
+                    self.errors.push(e);
+                    try!(self.skip_to_recovery_point());
+                    Ok(wrap(line, col, Token::Error))
+                },
+                _ => Err(e)
+            }
         }
     }
 
+    /// Discards characters up to (but not including) the next whitespace,
+    /// `(`, `)`, or `;`, so `lex_token_recovering` can resume lexing cleanly.
+    fn skip_to_recovery_point(&mut self) -> Result<(), ParserError> {
+        try!(self.read_while(|c| !is_whitespace(c) && c != '(' && c != ')' && c != ';'));
+        Ok(())
+    }
+
     /// return next token
     pub fn lex_token(&mut self) -> Result<TokenWrapper, ParserError> {
         try!(self.consume_whitespace());
@@ -122,18 +233,31 @@ impl <'a> Lexer<'a> {
             let mut init = String::new();
             init.push(c);
             self.lex_ident(init).map(|s| wrap(line, col, Token::Identifier(Cow::Owned(s))))
+        } else if c == '\\' {
+            // R6RS <initial> also allows an inline hex escape, e.g. \x3BB;oo for "λoo"
+            let mut init = String::new();
+            try!(self.lex_ident_hex_escape(&mut init));
+            self.lex_ident(init).map(|s| wrap(line, col, Token::Identifier(Cow::Owned(s))))
         } else if c == '+' && end_of_token {
             Ok(wrap(line, col, Token::Identifier(Cow::Borrowed("+"))))
+        } else if c == '+' {
+            match try!(self.try_lex_signed_numeric(c)) {
+                Some(s) => Ok(wrap(line, col, Token::Numeric(s))),
+                None => Err(self.make_error(ParserErrorKind::InvalidCharacter(c)))
+            }
         } else if c == '-' {
             if end_of_token {
                 Ok(wrap(line, col, Token::Identifier(Cow::Borrowed("-"))))
             } else {
-                match self.lookahead() {
-                    Ok('>') => self.lex_ident("-".to_string()).map(|s| wrap(line, col, Token::Identifier(Cow::Owned(s)))),
-                    Ok(c) => Err(self.make_error(ParserErrorKind::InvalidCharacter(c))),
-                    Err(e) => match e.kind {
-                        IoErrorKind::EndOfFile => Ok(wrap(line, col, Token::Identifier(Cow::Borrowed("-")))),
-                        _ => Err(self.make_error(ParserErrorKind::UnderlyingError(e)))
+                match try!(self.try_lex_signed_numeric(c)) {
+                    Some(s) => Ok(wrap(line, col, Token::Numeric(s))),
+                    None => match self.lookahead() {
+                        Ok('>') => self.lex_ident("-".to_string()).map(|s| wrap(line, col, Token::Identifier(Cow::Owned(s)))),
+                        Ok(c) => Err(self.make_error(ParserErrorKind::InvalidCharacter(c))),
+                        Err(e) => match e.kind {
+                            IoErrorKind::EndOfFile => Ok(wrap(line, col, Token::Identifier(Cow::Borrowed("-")))),
+                            _ => Err(self.make_error(ParserErrorKind::UnderlyingError(e)))
+                        }
                     }
                 }
             }
@@ -143,6 +267,8 @@ impl <'a> Lexer<'a> {
             Ok(wrap(line, col, Token::CloseParen))
         } else if c == '.' && end_of_token {
             Ok(wrap(line, col, Token::Dot))
+        } else if c == '"' {
+            self.lex_string(line, col).map(|(s, has_escape)| wrap(line, col, Token::Str(s, has_escape)))
         } else if c == '#' {
             let c0 = match self.consume() {
                 Err(e) => return Err(match e.kind {
@@ -154,9 +280,30 @@ impl <'a> Lexer<'a> {
             match c0 {
                 't' | 'T' => Ok(wrap(line, col, Token::True)),
                 'f' | 'F' => Ok(wrap(line, col, Token::False)),
-                '\\' => self.lex_char().map(|s| wrap(line, col, Token::Character(s))),
+                '\\' => self.lex_char().map(|c| wrap(line, col, Token::Character(c))),
+                'b' | 'B' | 'o' | 'O' | 'd' | 'D' | 'x' | 'X' | 'e' | 'E' | 'i' | 'I' =>
+                    self.lex_numeric_prefixed(c0).map(|s| wrap(line, col, Token::Numeric(s))),
+                '(' => Ok(wrap(line, col, Token::OpenVector)),
+                'v' | 'V' => if try!(self.try_consume_literal("u8(")) {
+                    Ok(wrap(line, col, Token::OpenBytevector))
+                } else {
+                    Err(self.make_error(ParserErrorKind::InvalidCharacter(c0)))
+                },
+                ';' => Ok(wrap(line, col, Token::DatumComment)),
                 _ => Err(self.make_error(ParserErrorKind::InvalidCharacter(c)))
             }
+        } else if c == '\'' {
+            Ok(wrap(line, col, Token::Quote))
+        } else if c == '`' {
+            Ok(wrap(line, col, Token::Quasiquote))
+        } else if c == ',' {
+            match try!(self.peek_char_opt()) {
+                Some('@') => {
+                    try!(self.consume());
+                    Ok(wrap(line, col, Token::UnquoteSplicing))
+                },
+                _ => Ok(wrap(line, col, Token::Unquote))
+            }
         } else if c.is_numeric() {
             self.lex_numeric(c).map(|s| wrap(line, col, Token::Numeric(s)))
         } else {
@@ -176,12 +323,58 @@ impl <'a> Lexer<'a> {
 
     fn lex_ident(&mut self, initial: String) -> Result<String, ParserError> {
         let mut s = initial;
-        let sub = try!(self.read_while(is_subsequent));
-        s.push_str(sub.as_slice());
-        return Ok(s);
+
+        loop {
+            let sub = try!(self.read_while(is_subsequent));
+            s.push_str(sub.as_slice());
+
+            match try!(self.peek_char_opt()) {
+                Some('\\') => {
+                    try!(self.consume());
+                    try!(self.lex_ident_hex_escape(&mut s));
+                },
+                _ => break
+            }
+        }
+
+        let normalized: String = s.as_slice().nfc().collect();
+        Ok(normalized)
+    }
+
+    /// Lexes a R6RS `\xHH...;` inline hex escape inside an identifier, having
+    /// already consumed the `\`.
+    fn lex_ident_hex_escape(&mut self, s: &mut String) -> Result<(), ParserError> {
+        match self.consume() {
+            Ok('x') | Ok('X') => (),
+            Ok(c) => return Err(self.make_error(ParserErrorKind::InvalidCharacter(c))),
+            Err(e) => return Err(match e.kind {
+                IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnexpectedEOF),
+                _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+            })
+        }
+
+        let hex = try!(self.read_while(|c| c.is_digit(16)));
+
+        match self.consume() {
+            Ok(';') => (),
+            Ok(c) => return Err(self.make_error(ParserErrorKind::InvalidCharacter(c))),
+            Err(e) => return Err(match e.kind {
+                IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnexpectedEOF),
+                _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+            })
+        }
+
+        match u32::from_str_radix(hex.as_slice(), 16).ok().and_then(char::from_u32) {
+            Some(c) => { s.push(c); Ok(()) },
+            None => Err(self.make_error(ParserErrorKind::InvalidCharacter('x')))
+        }
     }
 
-    fn lex_char(&mut self) -> Result<String, ParserError> {
+    /// Lexes a `#\<char>` literal, having already consumed the `\`. A single
+    /// non-alphabetic character (like `#\(`) is taken literally; a longer run
+    /// of alphanumerics is resolved as either a named character (`space`,
+    /// `newline`, ...) or a `xHH...` hex scalar value.
+    fn lex_char(&mut self) -> Result<char, ParserError> {
         let c = match self.consume() {
             Ok(c) => c,
             Err(e) => return Err(self.make_error(match e.kind {
@@ -190,19 +383,389 @@ impl <'a> Lexer<'a> {
             }))
         };
 
+        let mut name = String::new();
+        name.push(c);
+        let rest = try!(self.read_while(|c| c.is_alphanumeric()));
+        name.push_str(rest.as_slice());
+
+        if name.chars().count() == 1 {
+            return Ok(c);
+        }
+
+        if (c == 'x' || c == 'X') && name.as_slice()[1..].chars().all(|c| c.is_digit(16)) {
+            return match u32::from_str_radix(&name.as_slice()[1..], 16).ok().and_then(char::from_u32) {
+                Some(c) => Ok(c),
+                None => Err(self.make_error(ParserErrorKind::InvalidCharacterName(name)))
+            };
+        }
+
+        match name.as_slice() {
+            "space" => Ok(' '),
+            "newline" | "linefeed" | "nl" => Ok('\n'),
+            "tab" => Ok('\t'),
+            "return" => Ok('\r'),
+            "nul" | "null" => Ok('\x00'),
+            "delete" | "rubout" => Ok('\x7f'),
+            "altmode" | "escape" | "esc" => Ok('\x1b'),
+            "backspace" => Ok('\x08'),
+            "alarm" => Ok('\x07'),
+            _ => Err(self.make_error(ParserErrorKind::InvalidCharacterName(name)))
+        }
+    }
+
+    /// Lexes the body of a string literal, having already consumed the opening `"`.
+    /// Returns the unescaped contents and whether any escape sequence was seen.
+    fn lex_string(&mut self, open_line: usize, open_col: usize) -> Result<(String, bool), ParserError> {
         let mut s = String::new();
-        s.push(c);
-        let sub = try!(self.read_while(|c| c.is_alphanumeric()));
-        s.push_str(sub.as_slice());
-        return Ok(s);
+        let mut has_escape = false;
+
+        loop {
+            let c = match self.consume() {
+                Ok(c) => c,
+                Err(e) => return Err(match e.kind {
+                    IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnterminatedString(open_line, open_col)),
+                    _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+                })
+            };
+
+            if c == '"' {
+                return Ok((s, has_escape));
+            } else if c == '\\' {
+                has_escape = true;
+                try!(self.lex_string_escape(&mut s, open_line, open_col));
+            } else {
+                s.push(c);
+            }
+        }
     }
 
+    /// Lexes a single backslash escape inside a string literal, pushing the
+    /// resulting character(s) onto `s`. The backslash itself must already be consumed.
+    fn lex_string_escape(&mut self, s: &mut String, open_line: usize, open_col: usize) -> Result<(), ParserError> {
+        let c = match self.consume() {
+            Ok(c) => c,
+            Err(e) => return Err(match e.kind {
+                IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnterminatedString(open_line, open_col)),
+                _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+            })
+        };
+
+        match c {
+            'n' => { s.push('\n'); Ok(()) },
+            't' => { s.push('\t'); Ok(()) },
+            'r' => { s.push('\r'); Ok(()) },
+            '\\' => { s.push('\\'); Ok(()) },
+            '"' => { s.push('"'); Ok(()) },
+            'a' => { s.push('\x07'); Ok(()) },
+            'b' => { s.push('\x08'); Ok(()) },
+            'x' | 'X' => self.lex_string_hex_escape(s, open_line, open_col),
+            c if c == '\n' || is_intraline_whitespace(c) => self.lex_line_continuation(c, open_line, open_col),
+            c => Err(self.make_error(ParserErrorKind::InvalidCharacter(c)))
+        }
+    }
+
+    /// Lexes a `\xHH...;` hex scalar value escape, having already consumed the `x`.
+    fn lex_string_hex_escape(&mut self, s: &mut String, open_line: usize, open_col: usize) -> Result<(), ParserError> {
+        let hex = try!(self.read_while(|c| c.is_digit(16)));
+
+        match self.consume() {
+            Ok(';') => (),
+            Ok(c) => return Err(self.make_error(ParserErrorKind::InvalidCharacter(c))),
+            Err(e) => return Err(match e.kind {
+                IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnterminatedString(open_line, open_col)),
+                _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+            })
+        }
+
+        match u32::from_str_radix(hex.as_slice(), 16).ok().and_then(char::from_u32) {
+            Some(c) => { s.push(c); Ok(()) },
+            None => Err(self.make_error(ParserErrorKind::InvalidCharacter('x')))
+        }
+    }
+
+    /// Elides a `\` followed by intraline whitespace, a newline, and more intraline
+    /// whitespace, per R6RS. `first` is the character already consumed after the `\`.
+    fn lex_line_continuation(&mut self, first: char, open_line: usize, open_col: usize) -> Result<(), ParserError> {
+        let mut c = first;
+
+        if c != '\n' {
+            try!(self.read_while(is_intraline_whitespace));
+            c = match self.consume() {
+                Ok(c) => c,
+                Err(e) => return Err(match e.kind {
+                    IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnterminatedString(open_line, open_col)),
+                    _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+                })
+            };
+        }
+
+        if c != '\n' {
+            return Err(self.make_error(ParserErrorKind::InvalidCharacter(c)));
+        }
+
+        try!(self.read_while(is_intraline_whitespace));
+        Ok(())
+    }
+
+    /// Lexes an unsigned, unprefixed decimal numeric token, having already
+    /// consumed its first digit as `init`.
     fn lex_numeric(&mut self, init: char) -> Result<String, ParserError> {
         let mut s = String::new();
         s.push(init);
-        let sub = try!(self.read_while(|c| c.is_numeric()));
-        s.push_str(sub.as_slice());
-        return Ok(s);
+        try!(self.lex_ureal_cont(10, &mut s, false));
+        try!(self.lex_complex_suffix(10, &mut s));
+        Ok(s)
+    }
+
+    /// Lexes a `#b #o #d #x #e #i`-prefixed numeric token, having already consumed
+    /// the leading `#` and `first` (the letter after it). Radix and exactness
+    /// prefixes may appear in either order and combine, e.g. `#x#e1f`.
+    fn lex_numeric_prefixed(&mut self, first: char) -> Result<String, ParserError> {
+        let mut s = String::new();
+        s.push('#');
+        s.push(first);
+
+        let mut radix = radix_for(first);
+        let mut has_exactness = radix.is_none();
+
+        if let Some('#') = try!(self.peek_char_opt()) {
+            try!(self.consume());
+            s.push('#');
+
+            let second = match self.consume() {
+                Ok(c) => c,
+                Err(e) => return Err(match e.kind {
+                    IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnexpectedEOF),
+                    _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+                })
+            };
+            s.push(second);
+
+            match radix_for(second) {
+                Some(r) => {
+                    if radix.is_some() {
+                        return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s)));
+                    }
+                    radix = Some(r);
+                },
+                None if is_exactness_marker(second) => {
+                    if has_exactness {
+                        return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s)));
+                    }
+                    has_exactness = true;
+                },
+                None => return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s)))
+            }
+        }
+
+        let radix = radix.unwrap_or(10);
+
+        match try!(self.peek_char_opt()) {
+            Some(sign @ '+') | Some(sign @ '-') => {
+                try!(self.consume());
+                s.push(sign);
+                let mut matched = false;
+                if try!(self.try_consume_literal("inf.0")) {
+                    s.push_str("inf.0");
+                    matched = true;
+                } else if try!(self.try_consume_literal("nan.0")) {
+                    s.push_str("nan.0");
+                    matched = true;
+                } else if let Some(c) = try!(self.peek_char_opt()) {
+                    if c.is_digit(radix) || (radix == 10 && c == '.') {
+                        try!(self.lex_ureal(radix, &mut s));
+                        matched = true;
+                    }
+                }
+
+                if !matched {
+                    // Nothing but the sign itself was consumed, so the only
+                    // valid reading left is a bare imaginary unit like #x+i.
+                    return match try!(self.peek_char_opt()) {
+                        Some(i @ 'i') | Some(i @ 'I') => {
+                            try!(self.consume());
+                            s.push(i);
+                            Ok(s)
+                        },
+                        _ => Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s)))
+                    };
+                }
+            },
+            _ => try!(self.lex_ureal(radix, &mut s))
+        }
+
+        try!(self.lex_complex_suffix(radix, &mut s));
+        Ok(s)
+    }
+
+    /// Tries to lex a signed numeric token, having already consumed the leading
+    /// `sign`. Returns `None` without consuming anything further if what follows
+    /// isn't the start of a number, so the caller can fall back to identifier lexing.
+    fn try_lex_signed_numeric(&mut self, sign: char) -> Result<Option<String>, ParserError> {
+        let mut s = String::new();
+        s.push(sign);
+
+        if try!(self.try_consume_literal("inf.0")) {
+            s.push_str("inf.0");
+            try!(self.lex_complex_suffix(10, &mut s));
+            return Ok(Some(s));
+        }
+        if try!(self.try_consume_literal("nan.0")) {
+            s.push_str("nan.0");
+            try!(self.lex_complex_suffix(10, &mut s));
+            return Ok(Some(s));
+        }
+
+        match try!(self.peek_char_opt()) {
+            Some(c1) if c1.is_digit(10) || c1 == '.' => {
+                try!(self.lex_ureal(10, &mut s));
+                try!(self.lex_complex_suffix(10, &mut s));
+                Ok(Some(s))
+            },
+            Some(i @ 'i') | Some(i @ 'I') => {
+                try!(self.consume());
+                s.push(i);
+                Ok(Some(s))
+            },
+            _ => Ok(None)
+        }
+    }
+
+    /// Lexes an unsigned real (`<uinteger>`, `<uinteger>/<uinteger>`, or in base 10
+    /// a decimal with optional exponent), none of which has been consumed yet.
+    fn lex_ureal(&mut self, radix: u32, s: &mut String) -> Result<(), ParserError> {
+        if radix == 10 {
+            if let Some('.') = try!(self.peek_char_opt()) {
+                try!(self.consume());
+                s.push('.');
+                let frac = try!(self.read_while(|c| c.is_digit(10)));
+                if frac.len() == 0 {
+                    return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s.clone())));
+                }
+                s.push_str(frac.as_slice());
+                return self.lex_exponent(s);
+            }
+        }
+
+        self.lex_ureal_cont(radix, s, true)
+    }
+
+    /// Reads the run of digits that continues an unsigned real already started
+    /// in `s` (or, if `require_digit`, must still provide at least one digit),
+    /// then an optional `/<uinteger>` denominator or, in base 10, a `.`/exponent tail.
+    fn lex_ureal_cont(&mut self, radix: u32, s: &mut String, require_digit: bool) -> Result<(), ParserError> {
+        let digits = try!(self.read_while(|c| c.is_digit(radix)));
+        if require_digit && digits.len() == 0 {
+            return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s.clone())));
+        }
+        s.push_str(digits.as_slice());
+
+        match try!(self.peek_char_opt()) {
+            Some('/') => {
+                try!(self.consume());
+                s.push('/');
+                let den = try!(self.read_while(|c| c.is_digit(radix)));
+                if den.len() == 0 {
+                    return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s.clone())));
+                }
+                s.push_str(den.as_slice());
+                Ok(())
+            },
+            Some('.') if radix == 10 => {
+                try!(self.consume());
+                s.push('.');
+                let frac = try!(self.read_while(|c| c.is_digit(10)));
+                s.push_str(frac.as_slice());
+                self.lex_exponent(s)
+            },
+            _ => if radix == 10 { self.lex_exponent(s) } else { Ok(()) }
+        }
+    }
+
+    /// Lexes an optional base-10 exponent marker (`e`, or `s f d l` per R6RS)
+    /// followed by a signed integer.
+    fn lex_exponent(&mut self, s: &mut String) -> Result<(), ParserError> {
+        match try!(self.peek_char_opt()) {
+            Some(m) if is_exponent_marker(m) => {
+                try!(self.consume());
+                s.push(m);
+
+                if let Some(sign @ '+') = try!(self.peek_char_opt()) {
+                    try!(self.consume());
+                    s.push(sign);
+                } else if let Some(sign @ '-') = try!(self.peek_char_opt()) {
+                    try!(self.consume());
+                    s.push(sign);
+                }
+
+                let digits = try!(self.read_while(|c| c.is_digit(10)));
+                if digits.len() == 0 {
+                    return Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s.clone())));
+                }
+                s.push_str(digits.as_slice());
+                Ok(())
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Lexes the optional complex-number tail that follows a real number: a polar
+    /// `@<real>` angle, a trailing bare `i`, or a rectangular `<sign>[<ureal>]i` part.
+    fn lex_complex_suffix(&mut self, radix: u32, s: &mut String) -> Result<(), ParserError> {
+        match try!(self.peek_char_opt()) {
+            Some('@') => {
+                try!(self.consume());
+                s.push('@');
+                self.lex_signed_real(radix, s)
+            },
+            Some(i @ 'i') | Some(i @ 'I') => {
+                try!(self.consume());
+                s.push(i);
+                Ok(())
+            },
+            Some(sign @ '+') | Some(sign @ '-') => {
+                try!(self.consume());
+                s.push(sign);
+
+                if try!(self.try_consume_literal("inf.0")) {
+                    s.push_str("inf.0");
+                } else if try!(self.try_consume_literal("nan.0")) {
+                    s.push_str("nan.0");
+                } else if let Some(c) = try!(self.peek_char_opt()) {
+                    if c.is_digit(radix) || (radix == 10 && c == '.') {
+                        try!(self.lex_ureal(radix, s));
+                    }
+                }
+
+                match try!(self.peek_char_opt()) {
+                    Some(i @ 'i') | Some(i @ 'I') => { try!(self.consume()); s.push(i); Ok(()) },
+                    _ => Err(self.make_error(ParserErrorKind::InvalidNumericLiteral(s.clone())))
+                }
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Lexes a signed real used as the angle of a polar complex literal.
+    fn lex_signed_real(&mut self, radix: u32, s: &mut String) -> Result<(), ParserError> {
+        if let Some(sign @ '+') = try!(self.peek_char_opt()) {
+            try!(self.consume());
+            s.push(sign);
+        } else if let Some(sign @ '-') = try!(self.peek_char_opt()) {
+            try!(self.consume());
+            s.push(sign);
+        }
+
+        if try!(self.try_consume_literal("inf.0")) {
+            s.push_str("inf.0");
+            return Ok(());
+        }
+        if try!(self.try_consume_literal("nan.0")) {
+            s.push_str("nan.0");
+            return Ok(());
+        }
+
+        self.lex_ureal(radix, s)
     }
 
     fn make_error(&self, kind: ParserErrorKind) -> ParserError {
@@ -214,6 +777,10 @@ impl <'a> Lexer<'a> {
     }
 
     fn lookahead(&mut self) -> Result<char, IoError> {
+        if let Some(&c) = self.pushback.last() {
+            return Ok(c);
+        }
+
         Ok(match self.lookahead_buf {
             Some(c) => c,
             None => {
@@ -236,26 +803,14 @@ impl <'a> Lexer<'a> {
     fn read_while<F>(&mut self, f: F) -> Result<String, ParserError> where
         F: Fn(char) -> bool
     {
-        let mut s = match self.lookahead_buf {
-            None => String::new(),
-            Some(c) => if f(c) {
-                self.lookahead_buf = None;
-                self.advance(c);
-                let mut s = String::new();
-                s.push(c);
-                s
-            } else {
-                return Ok(String::new());
-            }
-        };
+        let mut s = String::new();
 
         loop {
-            match self.stream.read_char() {
+            match self.consume() {
                 Ok(c) => if f(c) {
-                    self.advance(c);
                     s.push(c);
                 } else {
-                    self.lookahead_buf = Some(c);
+                    self.unconsume(c);
                     return Ok(s);
                 },
                 Err(e) => match e.kind {
@@ -267,18 +822,73 @@ impl <'a> Lexer<'a> {
     }
 
     fn consume(&mut self) -> Result<char, IoError> {
-        let c = match self.lookahead_buf {
-            Some(c) => {
-                self.lookahead_buf = None;
-                c
-            },
-            None => try!(self.stream.read_char())
+        let c = match self.pushback.pop() {
+            Some(c) => c,
+            None => match self.lookahead_buf {
+                Some(c) => {
+                    self.lookahead_buf = None;
+                    c
+                },
+                None => try!(self.stream.read_char())
+            }
         };
 
         self.advance(c);
         Ok(c)
     }
 
+    /// Puts `c` back so the next `consume`/`lookahead` sees it again, undoing
+    /// the position tracking from the `consume` that originally read it
+    fn unconsume(&mut self, c: char) {
+        if c == '\n' {
+            self.line -= 1;
+        } else {
+            self.column -= 1;
+        }
+        self.pushback.push(c);
+    }
+
+    /// Like `lookahead`, but returns `None` instead of an `IoError` on EOF
+    fn peek_char_opt(&mut self) -> Result<Option<char>, ParserError> {
+        match self.lookahead() {
+            Ok(c) => Ok(Some(c)),
+            Err(e) => match e.kind {
+                IoErrorKind::EndOfFile => Ok(None),
+                _ => Err(self.make_error(ParserErrorKind::UnderlyingError(e)))
+            }
+        }
+    }
+
+    /// Tries to consume exactly `lit`; consumes and returns `true` on a full match,
+    /// otherwise restores every character it looked at and returns `false`
+    fn try_consume_literal(&mut self, lit: &str) -> Result<bool, ParserError> {
+        let mut taken = Vec::new();
+
+        for expected in lit.chars() {
+            match self.consume() {
+                Ok(c) if c == expected => taken.push(c),
+                Ok(c) => {
+                    self.unconsume(c);
+                    for pc in taken.into_iter().rev() {
+                        self.unconsume(pc);
+                    }
+                    return Ok(false);
+                },
+                Err(e) => match e.kind {
+                    IoErrorKind::EndOfFile => {
+                        for pc in taken.into_iter().rev() {
+                            self.unconsume(pc);
+                        }
+                        return Ok(false);
+                    },
+                    _ => return Err(self.make_error(ParserErrorKind::UnderlyingError(e)))
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
     fn consume_whitespace(&mut self) -> Result<bool, ParserError> {
         let mut consumed = false;
         loop {
@@ -288,9 +898,12 @@ impl <'a> Lexer<'a> {
                 Ok(';') => {
                     consumed = true;
                     try!(self.read_while(|c| c != '\n'));
-                    if self.lookahead_buf.is_some() {
-                        self.lookahead_buf = None
-                    }
+                },
+                Ok('#') => if try!(self.try_consume_literal("#|")) {
+                    consumed = true;
+                    try!(self.skip_block_comment());
+                } else {
+                    return Ok(consumed);
                 },
                 Ok(_) => return Ok(consumed),
                 Err(e) => match e.kind {
@@ -300,4 +913,297 @@ impl <'a> Lexer<'a> {
             }
         }
     }
+
+    /// Skips a `#| ... |#` block comment, having already consumed the opening
+    /// `#|`. Nested block comments only close at depth 0.
+    fn skip_block_comment(&mut self) -> Result<(), ParserError> {
+        let mut depth = 1u32;
+
+        while depth > 0 {
+            if try!(self.try_consume_literal("#|")) {
+                depth += 1;
+            } else if try!(self.try_consume_literal("|#")) {
+                depth -= 1;
+            } else {
+                match self.consume() {
+                    Ok(_) => (),
+                    Err(e) => return Err(match e.kind {
+                        IoErrorKind::EndOfFile => self.make_error(ParserErrorKind::UnexpectedEOF),
+                        _ => self.make_error(ParserErrorKind::UnderlyingError(e))
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+    use std::borrow::Cow;
+
+    use super::{Lexer, Token};
+    use error::ParserErrorKind;
+
+    fn lex_one(src: &str) -> Token {
+        let mut reader = BufReader::new(src.as_bytes());
+        let mut lexer = Lexer::new(&mut reader);
+        lexer.lex_token().unwrap().token
+    }
+
+    fn ident(s: &str) -> Token {
+        Token::Identifier(Cow::Owned(s.to_string()))
+    }
+
+    fn lex_err(src: &str) -> ParserErrorKind {
+        let mut reader = BufReader::new(src.as_bytes());
+        let mut lexer = Lexer::new(&mut reader);
+        lexer.lex_token().unwrap_err().kind
+    }
+
+    #[test]
+    fn lexes_plain_string() {
+        assert!(lex_one("\"hello\"") == Token::Str("hello".to_string(), false));
+    }
+
+    #[test]
+    fn lexes_backslash_escapes() {
+        assert!(lex_one("\"a\\nb\\t\\\"c\"") == Token::Str("a\nb\t\"c".to_string(), true));
+    }
+
+    #[test]
+    fn lexes_hex_escape() {
+        assert!(lex_one("\"\\x3BB;\"") == Token::Str("\u{3BB}".to_string(), true));
+    }
+
+    #[test]
+    fn lexes_line_continuation_as_nothing() {
+        assert!(lex_one("\"a\\\n   b\"") == Token::Str("ab".to_string(), true));
+    }
+
+    #[test]
+    fn unterminated_string_reports_opening_position() {
+        match lex_err("\"abc") {
+            ParserErrorKind::UnterminatedString(1, 1) => (),
+            other => panic!("expected UnterminatedString(1, 1), got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn lexes_plain_integer() {
+        assert!(lex_one("42") == Token::Numeric("42".to_string()));
+    }
+
+    #[test]
+    fn lexes_signed_integer() {
+        assert!(lex_one("-17") == Token::Numeric("-17".to_string()));
+    }
+
+    #[test]
+    fn lexes_rational() {
+        assert!(lex_one("3/4") == Token::Numeric("3/4".to_string()));
+    }
+
+    #[test]
+    fn lexes_decimal_with_exponent() {
+        assert!(lex_one("1.5e10") == Token::Numeric("1.5e10".to_string()));
+    }
+
+    #[test]
+    fn lexes_infnan() {
+        assert!(lex_one("+inf.0") == Token::Numeric("+inf.0".to_string()));
+        assert!(lex_one("-nan.0") == Token::Numeric("-nan.0".to_string()));
+    }
+
+    #[test]
+    fn lexes_hex_radix_prefix() {
+        assert!(lex_one("#x1f") == Token::Numeric("#x1f".to_string()));
+    }
+
+    #[test]
+    fn lexes_combined_radix_and_exactness_prefixes() {
+        assert!(lex_one("#x#e1f") == Token::Numeric("#x#e1f".to_string()));
+        assert!(lex_one("#e#x1f") == Token::Numeric("#e#x1f".to_string()));
+    }
+
+    #[test]
+    fn rejects_two_radix_prefixes() {
+        match lex_err("#x#o1") {
+            ParserErrorKind::InvalidNumericLiteral(_) => (),
+            other => panic!("expected InvalidNumericLiteral, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn lexes_polar_complex() {
+        assert!(lex_one("1@2") == Token::Numeric("1@2".to_string()));
+    }
+
+    #[test]
+    fn lexes_rectangular_complex() {
+        assert!(lex_one("1+2i") == Token::Numeric("1+2i".to_string()));
+    }
+
+    #[test]
+    fn lexes_bare_prefixed_imaginary_unit() {
+        assert!(lex_one("#x+i") == Token::Numeric("#x+i".to_string()));
+    }
+
+    #[test]
+    fn lexes_bare_imaginary_unit() {
+        assert!(lex_one("+i") == Token::Numeric("+i".to_string()));
+        assert!(lex_one("-i") == Token::Numeric("-i".to_string()));
+    }
+
+    #[test]
+    fn rejects_bare_sign_prefixed_numeric() {
+        match lex_err("#x+)") {
+            ParserErrorKind::InvalidNumericLiteral(_) => (),
+            other => panic!("expected InvalidNumericLiteral, got {:?}", other)
+        }
+        match lex_err("#x+") {
+            ParserErrorKind::InvalidNumericLiteral(_) => (),
+            other => panic!("expected InvalidNumericLiteral, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_lex_token_fails_fast_on_invalid_character() {
+        let mut reader = BufReader::new("{".as_bytes());
+        let mut lexer = Lexer::new(&mut reader);
+        match lexer.lex_token() {
+            Err(e) => match e.kind {
+                ParserErrorKind::InvalidCharacter('{') => (),
+                other => panic!("expected InvalidCharacter('{{'), got {:?}", other)
+            },
+            Ok(_) => panic!("expected strict lex_token to fail on an invalid character")
+        }
+    }
+
+    #[test]
+    fn recovers_from_invalid_character_and_keeps_lexing() {
+        let mut reader = BufReader::new("{ 42".as_bytes());
+        let mut lexer = Lexer::new(&mut reader);
+
+        let first = lexer.lex_token_recovering().unwrap().token;
+        assert!(first == Token::Error);
+
+        let errors = lexer.take_errors();
+        assert_eq!(errors.len(), 1);
+        match errors[0].kind {
+            ParserErrorKind::InvalidCharacter('{') => (),
+            ref other => panic!("expected InvalidCharacter('{{'), got {:?}", other)
+        }
+
+        let second = lexer.lex_token_recovering().unwrap().token;
+        assert!(second == Token::Numeric("42".to_string()));
+    }
+
+    #[test]
+    fn take_errors_drains_what_it_returns() {
+        let mut reader = BufReader::new("{ 1".as_bytes());
+        let mut lexer = Lexer::new(&mut reader);
+
+        lexer.lex_token_recovering().unwrap();
+        assert_eq!(lexer.take_errors().len(), 1);
+        assert_eq!(lexer.take_errors().len(), 0);
+    }
+
+    #[test]
+    fn lexes_unicode_identifier() {
+        assert!(lex_one("\u{3bb}") == ident("\u{3bb}"));
+    }
+
+    #[test]
+    fn lexes_accented_identifier() {
+        assert!(lex_one("caf\u{e9}") == ident("caf\u{e9}"));
+    }
+
+    #[test]
+    fn nfc_normalizes_combining_form() {
+        // 'e' followed by a combining acute accent should normalize to the
+        // single precomposed "\u{e9}" (e with acute)
+        assert!(lex_one("e\u{301}") == ident("\u{e9}"));
+    }
+
+    #[test]
+    fn lexes_hex_escape_in_subsequent_position() {
+        assert!(lex_one("fo\\x6F;") == ident("foo"));
+    }
+
+    #[test]
+    fn lexes_hex_escape_in_initial_position() {
+        assert!(lex_one("\\x3BB;oo") == ident("\u{3bb}oo"));
+    }
+
+    #[test]
+    fn lexes_quote() {
+        assert!(lex_one("'") == Token::Quote);
+    }
+
+    #[test]
+    fn lexes_quasiquote() {
+        assert!(lex_one("`") == Token::Quasiquote);
+    }
+
+    #[test]
+    fn lexes_unquote() {
+        assert!(lex_one(",x") == Token::Unquote);
+    }
+
+    #[test]
+    fn lexes_unquote_splicing() {
+        assert!(lex_one(",@x") == Token::UnquoteSplicing);
+    }
+
+    #[test]
+    fn lexes_open_vector() {
+        assert!(lex_one("#(") == Token::OpenVector);
+    }
+
+    #[test]
+    fn lexes_open_bytevector() {
+        assert!(lex_one("#vu8(") == Token::OpenBytevector);
+    }
+
+    #[test]
+    fn skips_block_comment() {
+        assert!(lex_one("#| a comment |# 42") == Token::Numeric("42".to_string()));
+    }
+
+    #[test]
+    fn skips_nested_block_comment() {
+        assert!(lex_one("#| outer #| inner |# still outer |# 42") == Token::Numeric("42".to_string()));
+    }
+
+    #[test]
+    fn lexes_datum_comment() {
+        assert!(lex_one("#;") == Token::DatumComment);
+    }
+
+    #[test]
+    fn lexes_single_non_alphabetic_character_literally() {
+        assert!(lex_one("#\\(") == Token::Character('('));
+    }
+
+    #[test]
+    fn lexes_named_character() {
+        assert!(lex_one("#\\space") == Token::Character(' '));
+        assert!(lex_one("#\\newline") == Token::Character('\n'));
+    }
+
+    #[test]
+    fn lexes_hex_character() {
+        assert!(lex_one("#\\x41") == Token::Character('A'));
+    }
+
+    #[test]
+    fn rejects_unknown_character_name() {
+        match lex_err("#\\bogus") {
+            ParserErrorKind::InvalidCharacterName(_) => (),
+            other => panic!("expected InvalidCharacterName, got {:?}", other)
+        }
+    }
 }